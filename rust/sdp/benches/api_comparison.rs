@@ -117,6 +117,21 @@ fn bench_slice_decode_string(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_slice_decode_str_ref(c: &mut Criterion) {
+    let mut buf = [0u8; 100];
+    wire_slice::encode_string(&mut buf, 0, TEST_STRING).unwrap();
+
+    let mut group = c.benchmark_group("slice/string");
+    group.throughput(Throughput::Bytes(TEST_STRING.len() as u64));
+
+    group.bench_function("decode_str_ref", |b| {
+        b.iter(|| {
+            black_box(wire_slice::decode_str_ref(&buf, 0).unwrap());
+        });
+    });
+    group.finish();
+}
+
 // ============================================================================
 // Complex roundtrip: Multiple values
 // ============================================================================
@@ -185,6 +200,7 @@ criterion_group!(
     bench_slice_decode_u32,
     bench_slice_encode_string,
     bench_slice_decode_string,
+    bench_slice_decode_str_ref,
     bench_trait_complex_roundtrip,
     bench_slice_complex_roundtrip
 );
@@ -0,0 +1,57 @@
+// Bulk numeric array decode benchmarks
+// Compares the `_array_fast` helpers against a naive per-element decode loop.
+// Both decode from offset 0 of a freshly allocated Vec<u8>, so the helpers
+// take whichever path that buffer's alignment selects; the memcpy and
+// unaligned-loop paths for misaligned input are not benchmarked here.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use sdp::wire_slice::{decode_u32_array_fast, decode_u64_array_fast, encode_u32_array_fast};
+
+fn bench_decode_u32_array_fast(c: &mut Criterion) {
+    let values: Vec<u32> = (0..10_000).map(|i| i.wrapping_mul(2654435761)).collect();
+    let mut buf = vec![0u8; values.len() * 4];
+    encode_u32_array_fast(&mut buf, 0, &values).unwrap();
+
+    let mut group = c.benchmark_group("bulk_decode/u32");
+    group.throughput(Throughput::Elements(values.len() as u64));
+    group.bench_function("array_fast", |b| {
+        b.iter(|| black_box(decode_u32_array_fast(black_box(&buf), 0, values.len()).unwrap()));
+    });
+    group.bench_function("scalar_loop", |b| {
+        b.iter(|| {
+            let mut out = Vec::with_capacity(values.len());
+            for chunk in black_box(&buf).chunks_exact(4) {
+                out.push(u32::from_le_bytes(chunk.try_into().unwrap()));
+            }
+            black_box(out);
+        });
+    });
+    group.finish();
+}
+
+fn bench_decode_u64_array_fast(c: &mut Criterion) {
+    let values: Vec<u64> = (0..10_000u64).map(|i| i.wrapping_mul(0x9E3779B97F4A7C15)).collect();
+    let mut buf = vec![0u8; values.len() * 8];
+    for (i, &v) in values.iter().enumerate() {
+        buf[i * 8..i * 8 + 8].copy_from_slice(&v.to_le_bytes());
+    }
+
+    let mut group = c.benchmark_group("bulk_decode/u64");
+    group.throughput(Throughput::Elements(values.len() as u64));
+    group.bench_function("array_fast", |b| {
+        b.iter(|| black_box(decode_u64_array_fast(black_box(&buf), 0, values.len()).unwrap()));
+    });
+    group.bench_function("scalar_loop", |b| {
+        b.iter(|| {
+            let mut out = Vec::with_capacity(values.len());
+            for chunk in black_box(&buf).chunks_exact(8) {
+                out.push(u64::from_le_bytes(chunk.try_into().unwrap()));
+            }
+            black_box(out);
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_decode_u32_array_fast, bench_decode_u64_array_fast);
+criterion_main!(benches);
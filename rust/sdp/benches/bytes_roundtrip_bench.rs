@@ -0,0 +1,33 @@
+// Buf/BufMut API vs trait-based Read/Write API: a reusable BytesMut that
+// gets reset and re-encoded into on every iteration replaces the fresh
+// per-message Vec::new() the trait API encodes into in
+// bench_trait_complex_roundtrip (api_comparison.rs). The decode side still
+// copies the encoded bytes into a new Bytes on every iteration.
+
+use bytes::{Bytes, BytesMut};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use sdp::wire_bytes::{Decoder, Encoder};
+
+fn bench_bytes_complex_roundtrip(c: &mut Criterion) {
+    let mut reusable = BytesMut::with_capacity(64);
+    c.bench_function("bytes/complex_roundtrip", |b| {
+        b.iter(|| {
+            reusable.clear();
+            let mut enc = Encoder::new(&mut reusable);
+
+            enc.write_u32(black_box(42)).unwrap();
+            enc.write_f64(black_box(3.14159)).unwrap();
+            enc.write_bool(black_box(true)).unwrap();
+            enc.write_string(black_box("test")).unwrap();
+
+            let mut dec = Decoder::new(Bytes::from(reusable.to_vec()));
+            black_box(dec.read_u32().unwrap());
+            black_box(dec.read_f64().unwrap());
+            black_box(dec.read_bool().unwrap());
+            black_box(dec.read_string().unwrap());
+        });
+    });
+}
+
+criterion_group!(benches, bench_bytes_complex_roundtrip);
+criterion_main!(benches);
@@ -0,0 +1,42 @@
+// Hex encode/decode benchmarks over a realistic encoded wire frame, so the
+// branchless nibble-to-ASCII fast path in to_hex, and the per-character
+// decode in from_hex, are protected from regressions.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use sdp::wire::Encoder;
+use sdp::wire_slice::{from_hex, to_hex};
+
+fn realistic_frame() -> Vec<u8> {
+    let mut buf = Vec::new();
+    let mut enc = Encoder::new(&mut buf);
+    for i in 0..32u32 {
+        enc.write_u32(i).unwrap();
+        enc.write_string("channel_name").unwrap();
+        enc.write_f64(i as f64 * 0.5).unwrap();
+    }
+    buf
+}
+
+fn bench_to_hex(c: &mut Criterion) {
+    let frame = realistic_frame();
+    let mut group = c.benchmark_group("hex/encode");
+    group.throughput(Throughput::Bytes(frame.len() as u64));
+    group.bench_function("to_hex", |b| {
+        b.iter(|| black_box(to_hex(black_box(&frame))));
+    });
+    group.finish();
+}
+
+fn bench_from_hex(c: &mut Criterion) {
+    let frame = realistic_frame();
+    let hex = to_hex(&frame);
+    let mut group = c.benchmark_group("hex/decode");
+    group.throughput(Throughput::Bytes(frame.len() as u64));
+    group.bench_function("from_hex", |b| {
+        b.iter(|| black_box(from_hex(black_box(&hex)).unwrap()));
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_to_hex, bench_from_hex);
+criterion_main!(benches);
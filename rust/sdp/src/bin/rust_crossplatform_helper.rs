@@ -64,6 +64,163 @@ fn decode_primitives(filename: &str) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// One canonical fixture for `dump-vectors`/`verify-vectors`: a `.bin` frame
+/// plus a plain-text sidecar manifest of the field values it must decode to,
+/// so a C/Python/JS port can be checked by diffing bytes and replaying the
+/// same corpus instead of ad-hoc epsilon comparisons baked into a command.
+struct Vector {
+    name: &'static str,
+    data: primitives::AllPrimitives,
+}
+
+fn conformance_vectors() -> Vec<Vector> {
+    vec![
+        Vector {
+            name: "all_primitives_canonical",
+            data: primitives::AllPrimitives {
+                u8_field: 255,
+                u16_field: 65535,
+                u32_field: 4_294_967_295,
+                u64_field: 18_446_744_073_709_551_615,
+                i8_field: -128,
+                i16_field: -32768,
+                i32_field: -2_147_483_648,
+                i64_field: -9_223_372_036_854_775_808,
+                f32_field: 3.14159,
+                f64_field: 2.718281828459045,
+                bool_field: true,
+                str_field: "Hello from Rust!".to_string(),
+            },
+        },
+        Vector {
+            name: "all_primitives_empty_string",
+            data: primitives::AllPrimitives {
+                u8_field: 0,
+                u16_field: 0,
+                u32_field: 0,
+                u64_field: 0,
+                i8_field: 0,
+                i16_field: 0,
+                i32_field: 0,
+                i64_field: 0,
+                f32_field: 0.0,
+                f64_field: 0.0,
+                bool_field: false,
+                str_field: String::new(),
+            },
+        },
+        Vector {
+            name: "all_primitives_multibyte_utf8",
+            data: primitives::AllPrimitives {
+                u8_field: 1,
+                u16_field: 2,
+                u32_field: 3,
+                u64_field: 4,
+                i8_field: -1,
+                i16_field: -2,
+                i32_field: -3,
+                i64_field: -4,
+                f32_field: 1.5,
+                f64_field: 2.5,
+                bool_field: true,
+                str_field: "héllo 世界 🎛️".to_string(),
+            },
+        },
+    ]
+}
+
+fn manifest_for(v: &primitives::AllPrimitives) -> String {
+    format!(
+        "type: AllPrimitives\n\
+         u8_field: {}\n\
+         u16_field: {}\n\
+         u32_field: {}\n\
+         u64_field: {}\n\
+         i8_field: {}\n\
+         i16_field: {}\n\
+         i32_field: {}\n\
+         i64_field: {}\n\
+         f32_field: {}\n\
+         f64_field: {}\n\
+         bool_field: {}\n\
+         str_field: {}\n",
+        v.u8_field,
+        v.u16_field,
+        v.u32_field,
+        v.u64_field,
+        v.i8_field,
+        v.i16_field,
+        v.i32_field,
+        v.i64_field,
+        v.f32_field,
+        v.f64_field,
+        v.bool_field,
+        v.str_field
+    )
+}
+
+fn parse_manifest_field<'a>(manifest: &'a str, key: &str) -> Option<&'a str> {
+    manifest
+        .lines()
+        .find_map(|line| line.strip_prefix(&format!("{key}: ")))
+}
+
+/// Write a `.bin` fixture plus a `.manifest` sidecar for every conformance
+/// vector into `dir`, so other-language ports can be validated against the
+/// Rust reference without re-deriving expected values by hand.
+fn dump_vectors(dir: &str) -> Result<(), Box<dyn std::error::Error>> {
+    fs::create_dir_all(dir)?;
+    for vector in conformance_vectors() {
+        let mut buf = vec![0u8; vector.data.encoded_size()];
+        vector.data.encode_to_slice(&mut buf)?;
+        fs::write(format!("{dir}/{}.bin", vector.name), &buf)?;
+        fs::write(
+            format!("{dir}/{}.manifest", vector.name),
+            manifest_for(&vector.data),
+        )?;
+        eprintln!("wrote {dir}/{}.bin ({} bytes)", vector.name, buf.len());
+    }
+    Ok(())
+}
+
+/// Decode the `.bin` fixture of every conformance vector in `dir` and check
+/// it against its `.manifest` sidecar and the Rust reference values,
+/// catching truncation or endianness bugs a lossy epsilon check would miss.
+fn verify_vectors(dir: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let mut failures = 0;
+    for vector in conformance_vectors() {
+        let bin_path = format!("{dir}/{}.bin", vector.name);
+        let manifest_path = format!("{dir}/{}.manifest", vector.name);
+        let file_data = fs::read(&bin_path)?;
+        let manifest = fs::read_to_string(&manifest_path)?;
+        let decoded = primitives::AllPrimitives::decode_from_slice(&file_data)?;
+
+        let expected_str = parse_manifest_field(&manifest, "str_field").unwrap_or("");
+        let expected_u8: u8 = parse_manifest_field(&manifest, "u8_field")
+            .unwrap_or("0")
+            .parse()?;
+
+        let mut ok = true;
+        ok &= decoded.str_field == expected_str;
+        ok &= decoded.u8_field == expected_u8;
+        ok &= manifest == manifest_for(&decoded);
+        ok &= decoded == vector.data;
+
+        if ok {
+            eprintln!("✓ {} matches manifest", vector.name);
+        } else {
+            eprintln!("✗ {} does not match manifest", vector.name);
+            eprintln!("  decoded: {:?}", decoded);
+            failures += 1;
+        }
+    }
+
+    if failures > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args: Vec<String> = env::args().collect();
 
@@ -72,6 +229,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         eprintln!("Commands:");
         eprintln!("  encode-primitives - Encode primitives and output binary to stdout");
         eprintln!("  decode-primitives <file> - Decode primitives from file");
+        eprintln!("  dump-vectors <dir> - Write canonical fixtures + manifests for all conformance vectors");
+        eprintln!("  verify-vectors <dir> - Decode fixtures in <dir> and check them against their manifests");
         std::process::exit(1);
     }
 
@@ -84,6 +243,20 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             }
             decode_primitives(&args[2])?;
         }
+        "dump-vectors" => {
+            if args.len() < 3 {
+                eprintln!("Error: dump-vectors requires a directory argument");
+                std::process::exit(1);
+            }
+            dump_vectors(&args[2])?;
+        }
+        "verify-vectors" => {
+            if args.len() < 3 {
+                eprintln!("Error: verify-vectors requires a directory argument");
+                std::process::exit(1);
+            }
+            verify_vectors(&args[2])?;
+        }
         cmd => {
             eprintln!("Unknown command: {}", cmd);
             std::process::exit(1);
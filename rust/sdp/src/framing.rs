@@ -0,0 +1,161 @@
+//! Push-based length-delimited frame buffering
+//!
+//! [`wire::FrameReader`](crate::wire::FrameReader) pulls bytes from a `Read`
+//! itself; this module is for transports that don't expose one at all --
+//! bytes arrive as chunks handed to you by something else (a packet demuxer,
+//! a message channel) and you just need to buffer them until a full frame
+//! is available. [`FrameDecoder::feed`] appends a chunk,
+//! [`FrameDecoder::next_frame`] returns the next complete value or
+//! `Ok(None)` if more bytes are needed.
+//!
+//! The wire frame is the same as [`wire::Encoder::write_message`](crate::wire::Encoder::write_message):
+//! a `u32` little-endian length prefix followed by that many payload bytes.
+
+use crate::wire::{Decode, Error, Result, MAX_ARRAY_SIZE};
+use byteorder::{ByteOrder, LittleEndian};
+
+/// Buffers fed byte chunks and yields decoded `T` values once a full frame
+/// has arrived, without ever reading from an I/O source itself.
+pub struct FrameDecoder<T> {
+    buf: Vec<u8>,
+    max_frame_len: u32,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T: Decode> FrameDecoder<T> {
+    /// Create a decoder bounding frame bodies by [`MAX_ARRAY_SIZE`]
+    pub fn new() -> Self {
+        Self::with_max_frame_len(MAX_ARRAY_SIZE)
+    }
+
+    /// Create a decoder that rejects a length prefix over `max_frame_len`
+    /// before allocating anything for the frame body
+    pub fn with_max_frame_len(max_frame_len: u32) -> Self {
+        Self {
+            buf: Vec::new(),
+            max_frame_len,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Append a chunk of bytes to the internal buffer. Does no parsing by
+    /// itself -- call [`next_frame`](Self::next_frame) afterwards to check
+    /// whether a full frame is now available.
+    pub fn feed(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Decode the next frame out of the buffered bytes, or `Ok(None)` if the
+    /// current frame hasn't fully arrived yet.
+    pub fn next_frame(&mut self) -> Result<Option<T>> {
+        if self.buf.len() < 4 {
+            return Ok(None);
+        }
+        let len = LittleEndian::read_u32(&self.buf[..4]);
+        if len > self.max_frame_len {
+            return Err(Error::ArrayTooLarge {
+                offset: 0,
+                size: len,
+                max: self.max_frame_len,
+            });
+        }
+        let total = 4 + len as usize;
+        if self.buf.len() < total {
+            return Ok(None);
+        }
+        let value = T::decode_from_slice(&self.buf[4..total])?;
+        self.buf.drain(..total);
+        Ok(Some(value))
+    }
+}
+
+impl<T: Decode> Default for FrameDecoder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Write one length-delimited frame: a `u32` byte-length prefix followed by
+/// `payload`, matching the framing [`FrameDecoder`] expects and the format
+/// [`wire::Encoder::write_message`](crate::wire::Encoder::write_message) writes to a `Write`.
+pub fn encode_framed(payload: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(4 + payload.len());
+    out.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    out.extend_from_slice(payload);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    impl Decode for Point {
+        fn decode_from_slice(buf: &[u8]) -> Result<Self> {
+            let mut dec = crate::wire::Decoder::new(buf);
+            Ok(Point {
+                x: dec.read_i32()?,
+                y: dec.read_i32()?,
+            })
+        }
+    }
+
+    fn encode_point(x: i32, y: i32) -> Vec<u8> {
+        let mut payload = Vec::new();
+        let mut enc = crate::wire::Encoder::new(&mut payload);
+        enc.write_i32(x).unwrap();
+        enc.write_i32(y).unwrap();
+        encode_framed(&payload)
+    }
+
+    #[test]
+    fn test_feed_whole_frame_at_once() {
+        let mut dec = FrameDecoder::<Point>::new();
+        dec.feed(&encode_point(1, 2));
+        let p = dec.next_frame().unwrap().unwrap();
+        assert_eq!((p.x, p.y), (1, 2));
+        assert!(dec.next_frame().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_feed_frame_split_across_calls() {
+        let frame = encode_point(3, 4);
+        let mut dec = FrameDecoder::<Point>::new();
+
+        dec.feed(&frame[..2]);
+        assert!(dec.next_frame().unwrap().is_none());
+
+        dec.feed(&frame[2..5]);
+        assert!(dec.next_frame().unwrap().is_none());
+
+        dec.feed(&frame[5..]);
+        let p = dec.next_frame().unwrap().unwrap();
+        assert_eq!((p.x, p.y), (3, 4));
+    }
+
+    #[test]
+    fn test_feed_multiple_frames_back_to_back() {
+        let mut dec = FrameDecoder::<Point>::new();
+        dec.feed(&encode_point(1, 1));
+        dec.feed(&encode_point(2, 2));
+
+        let first = dec.next_frame().unwrap().unwrap();
+        assert_eq!((first.x, first.y), (1, 1));
+        let second = dec.next_frame().unwrap().unwrap();
+        assert_eq!((second.x, second.y), (2, 2));
+        assert!(dec.next_frame().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_rejects_oversized_length_prefix() {
+        let mut dec = FrameDecoder::<Point>::with_max_frame_len(4);
+        dec.feed(&8u32.to_le_bytes());
+        let err = dec.next_frame().unwrap_err();
+        assert!(matches!(err, Error::ArrayTooLarge { max: 4, size: 8, .. }));
+    }
+}
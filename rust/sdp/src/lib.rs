@@ -19,6 +19,40 @@
 //!
 //! Choose `wire_slice` for maximum performance in hot paths.
 //!
+//! Generated types built on `wire` get streaming encode/decode for free:
+//! an `encode(&self, w: &mut impl Write)` / `decode(r: &mut impl Read)` pair
+//! that reads and writes fields incrementally through `wire::Encoder`/
+//! `wire::Decoder`, so large messages can be streamed to a socket or
+//! `BufWriter` without a staging buffer. Generated types can also get a
+//! lifetime-parameterized view alongside the owned struct -- e.g. a
+//! `ParameterView<'a>` with `display_name: &'a str` -- whose `decode_view(buf:
+//! &'a [u8])` calls `wire_slice::decode_str_ref`/`decode_bytes_ref` per field
+//! instead of `decode_string`/`decode_bytes`, so scanning a read-only buffer
+//! allocates only the outer `Vec` for repeated fields, not a `String`/`Vec<u8>`
+//! per scalar field. This crate's generator is not vendored in this source
+//! tree, so no generated impls or view types ship here, but the primitives
+//! they would call already live in `wire`/`wire_slice`.
+//!
+//! A schema-level `flags` field type (named single-bit accessors over one
+//! backing `uN`, so e.g. `is_writable`/`can_ramp` can't disagree with the
+//! byte that actually goes on the wire) needs no new wire primitive -- it
+//! would encode/decode the backing integer with the existing `encode_u8`/
+//! `encode_u16`/`encode_u32`/`encode_u64` functions exactly like any other
+//! integer field, and mask/shift it in generated getter/setter methods. That
+//! generation is schema-language and generator work with nothing in this
+//! runtime library to extend.
+//!
+//! Per-field `default`-filling and a generated `migrate` helper for reading
+//! an older buffer into a newer struct build on `wire::VersionHeader` and
+//! `Encoder::finish_versioned`/`Decoder::read_version_header`, which already
+//! carry the `struct_v` a buffer was written at. A generated `decode` would
+//! read that version, decode only the fields present at it, and fill the
+//! rest from each newer field's declared default; a generated `migrate` is
+//! the same decode run deliberately against an older `compat_v`. Picking
+//! which fields exist at which version and what their defaults are is
+//! schema-language and generator work -- there's no struct generator in
+//! this source tree to carry it.
+//!
 //! ## Wire Format
 //!
 //! SDP uses a simple, efficient binary encoding:
@@ -27,6 +61,14 @@
 //! - Strings are length-prefixed: `u32_length + utf8_bytes`
 //! - Arrays are length-prefixed: `u32_length + elements`
 //!
+//! ## `no_std`
+//!
+//! With the default `std` feature disabled, the crate builds under
+//! `no_std` + `alloc`: `wire_slice` has no `std::io` dependency to begin
+//! with, so its `encode_*`/`decode_*` functions and `Vec`/`String`-returning
+//! helpers are unaffected. `wire` (and the `async` feature) need `std::io`/
+//! tokio and are unavailable in a `no_std` build.
+//!
 //! ## Example
 //!
 //! ```rust,ignore
@@ -53,9 +95,22 @@
 //! assert_eq!(decoded.latency, 512);
 //! ```
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(feature = "std")]
 pub mod wire;
 pub mod wire_slice;
+#[cfg(feature = "async")]
+pub mod wire_async;
+#[cfg(feature = "bytes")]
+pub mod wire_bytes;
+#[cfg(feature = "std")]
+pub mod framing;
 
+#[cfg(feature = "std")]
 pub use wire::{Encoder, Decoder, Error, Result};
 
 /// Wire format version (semver-compatible)
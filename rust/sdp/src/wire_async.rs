@@ -0,0 +1,306 @@
+//! Async wire format encoding/decoding, behind the `async` feature
+//!
+//! Mirrors [`crate::wire`]'s bool, unsigned integer, float, string, bytes and
+//! message encoding (same little-endian layout, same `u32` length prefixes);
+//! signed integers, varints and ordered keys have no async methods here.
+//! Reads and writes go through `tokio::io::AsyncRead`/
+//! `AsyncWrite` so a generated type's `encode_to_async_writer`/
+//! `decode_from_async_reader` pair can run on a tokio network connection
+//! without blocking or a `spawn_blocking` trampoline.
+
+use crate::wire::{Error, Result};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Async encoder for SDP wire format
+pub struct AsyncEncoder<W: AsyncWrite + Unpin> {
+    writer: W,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncEncoder<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Encode a boolean (1 byte: 0 or 1)
+    pub async fn write_bool(&mut self, value: bool) -> Result<()> {
+        self.writer.write_u8(if value { 1 } else { 0 }).await?;
+        Ok(())
+    }
+
+    /// Encode an 8-bit unsigned integer
+    pub async fn write_u8(&mut self, value: u8) -> Result<()> {
+        self.writer.write_u8(value).await?;
+        Ok(())
+    }
+
+    /// Encode a 16-bit unsigned integer (little-endian)
+    pub async fn write_u16(&mut self, value: u16) -> Result<()> {
+        self.writer.write_u16_le(value).await?;
+        Ok(())
+    }
+
+    /// Encode a 32-bit unsigned integer (little-endian)
+    pub async fn write_u32(&mut self, value: u32) -> Result<()> {
+        self.writer.write_u32_le(value).await?;
+        Ok(())
+    }
+
+    /// Encode a 64-bit unsigned integer (little-endian)
+    pub async fn write_u64(&mut self, value: u64) -> Result<()> {
+        self.writer.write_u64_le(value).await?;
+        Ok(())
+    }
+
+    /// Encode a 32-bit IEEE 754 float (little-endian)
+    pub async fn write_f32(&mut self, value: f32) -> Result<()> {
+        self.writer.write_f32_le(value).await?;
+        Ok(())
+    }
+
+    /// Encode a 64-bit IEEE 754 float (little-endian)
+    pub async fn write_f64(&mut self, value: f64) -> Result<()> {
+        self.writer.write_f64_le(value).await?;
+        Ok(())
+    }
+
+    /// Encode a string (u32 length + UTF-8 bytes)
+    pub async fn write_string(&mut self, value: &str) -> Result<()> {
+        self.write_bytes(value.as_bytes()).await
+    }
+
+    /// Encode a byte array (u32 length + bytes)
+    pub async fn write_bytes(&mut self, value: &[u8]) -> Result<()> {
+        self.write_u32(value.len() as u32).await?;
+        self.writer.write_all(value).await?;
+        Ok(())
+    }
+
+    /// Write one length-delimited message: a `u32` byte-length prefix
+    /// followed by `payload`, matching [`crate::wire::Encoder::write_message`]
+    pub async fn write_message(&mut self, payload: &[u8]) -> Result<()> {
+        self.write_u32(payload.len() as u32).await?;
+        self.writer.write_all(payload).await?;
+        Ok(())
+    }
+}
+
+/// Async decoder for SDP wire format
+pub struct AsyncDecoder<R: AsyncRead + Unpin> {
+    reader: R,
+    /// Number of bytes consumed so far, used as the offset of `InvalidBool`,
+    /// `InvalidUtf8` and `ArrayTooLarge` errors; unlike the synchronous
+    /// [`crate::wire::Decoder`], a stream that ends early surfaces as
+    /// `Error::Io` without an offset.
+    bytes_read: u64,
+}
+
+impl<R: AsyncRead + Unpin> AsyncDecoder<R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            bytes_read: 0,
+        }
+    }
+
+    /// Current byte offset into the stream
+    pub fn position(&self) -> u64 {
+        self.bytes_read
+    }
+
+    /// Decode a boolean
+    pub async fn read_bool(&mut self) -> Result<bool> {
+        let offset = self.bytes_read;
+        let value = self.reader.read_u8().await?;
+        self.bytes_read += 1;
+        match value {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => Err(Error::InvalidBool { offset, value }),
+        }
+    }
+
+    /// Decode an 8-bit unsigned integer
+    pub async fn read_u8(&mut self) -> Result<u8> {
+        let value = self.reader.read_u8().await?;
+        self.bytes_read += 1;
+        Ok(value)
+    }
+
+    /// Decode a 16-bit unsigned integer (little-endian)
+    pub async fn read_u16(&mut self) -> Result<u16> {
+        let value = self.reader.read_u16_le().await?;
+        self.bytes_read += 2;
+        Ok(value)
+    }
+
+    /// Decode a 32-bit unsigned integer (little-endian)
+    pub async fn read_u32(&mut self) -> Result<u32> {
+        let value = self.reader.read_u32_le().await?;
+        self.bytes_read += 4;
+        Ok(value)
+    }
+
+    /// Decode a 64-bit unsigned integer (little-endian)
+    pub async fn read_u64(&mut self) -> Result<u64> {
+        let value = self.reader.read_u64_le().await?;
+        self.bytes_read += 8;
+        Ok(value)
+    }
+
+    /// Decode a 32-bit IEEE 754 float (little-endian)
+    pub async fn read_f32(&mut self) -> Result<f32> {
+        let value = self.reader.read_f32_le().await?;
+        self.bytes_read += 4;
+        Ok(value)
+    }
+
+    /// Decode a 64-bit IEEE 754 float (little-endian)
+    pub async fn read_f64(&mut self) -> Result<f64> {
+        let value = self.reader.read_f64_le().await?;
+        self.bytes_read += 8;
+        Ok(value)
+    }
+
+    /// Decode a string (u32 length + UTF-8 bytes)
+    pub async fn read_string(&mut self) -> Result<String> {
+        let offset = self.bytes_read;
+        let bytes = self.read_bytes().await?;
+        String::from_utf8(bytes).map_err(|source| Error::InvalidUtf8 { offset, source })
+    }
+
+    /// Decode a byte array (u32 length + bytes), bounded by `MAX_ARRAY_SIZE`
+    pub async fn read_bytes(&mut self) -> Result<Vec<u8>> {
+        let offset = self.bytes_read;
+        let len = self.read_u32().await?;
+        if len > crate::wire::MAX_ARRAY_SIZE {
+            return Err(Error::ArrayTooLarge {
+                offset,
+                size: len,
+                max: crate::wire::MAX_ARRAY_SIZE,
+            });
+        }
+        let mut buf = vec![0u8; len as usize];
+        self.reader.read_exact(&mut buf).await?;
+        self.bytes_read += buf.len() as u64;
+        Ok(buf)
+    }
+
+    /// Read one length-delimited message, or `Ok(None)` at a clean
+    /// end-of-stream, matching [`crate::wire::Decoder::read_message`]
+    pub async fn read_message(&mut self) -> Result<Option<Vec<u8>>> {
+        let offset = self.bytes_read;
+        let mut len_buf = [0u8; 4];
+        // Only a stream that ends before the first prefix byte is a clean
+        // end; running out inside the prefix is an error.
+        if self.reader.read(&mut len_buf[..1]).await? == 0 {
+            return Ok(None);
+        }
+        self.reader.read_exact(&mut len_buf[1..]).await?;
+        self.bytes_read += 4;
+        let len = u32::from_le_bytes(len_buf);
+        if len > crate::wire::MAX_ARRAY_SIZE {
+            return Err(Error::ArrayTooLarge {
+                offset,
+                size: len,
+                max: crate::wire::MAX_ARRAY_SIZE,
+            });
+        }
+        let mut buf = vec![0u8; len as usize];
+        self.reader.read_exact(&mut buf).await?;
+        self.bytes_read += buf.len() as u64;
+        Ok(Some(buf))
+    }
+}
+
+/// Implemented by generated message types that can be reconstructed from a
+/// single decoded frame body, mirroring [`crate::wire::Decode`] so
+/// [`AsyncStreamDecoder`] can yield them directly over an async reader.
+pub trait AsyncDecode: Sized {
+    fn decode_from_slice(buf: &[u8]) -> Result<Self>;
+}
+
+/// Decodes a sequence of length-delimited `T` values back-to-back from one
+/// async reader, awaiting more bytes only when the current frame is
+/// incomplete. Mirrors [`crate::wire::StreamDecoder`] for the async surface.
+pub struct AsyncStreamDecoder<R: AsyncRead + Unpin, T> {
+    decoder: AsyncDecoder<R>,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<R: AsyncRead + Unpin, T: AsyncDecode> AsyncStreamDecoder<R, T> {
+    pub fn new(reader: R) -> Self {
+        AsyncStreamDecoder {
+            decoder: AsyncDecoder::new(reader),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Decode the next frame, or `Ok(None)` at a clean end-of-stream.
+    pub async fn next_message(&mut self) -> Result<Option<T>> {
+        match self.decoder.read_message().await? {
+            Some(buf) => Ok(Some(T::decode_from_slice(&buf)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_async_roundtrip() {
+        let mut buf = Vec::new();
+        {
+            let mut enc = AsyncEncoder::new(&mut buf);
+            enc.write_u32(0x12345678).await.unwrap();
+            enc.write_string("hello async").await.unwrap();
+        }
+
+        let mut dec = AsyncDecoder::new(&buf[..]);
+        assert_eq!(dec.read_u32().await.unwrap(), 0x12345678);
+        assert_eq!(dec.read_string().await.unwrap(), "hello async");
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    impl AsyncDecode for Point {
+        fn decode_from_slice(buf: &[u8]) -> Result<Self> {
+            let mut dec = crate::wire::Decoder::new(buf);
+            Ok(Point {
+                x: dec.read_i32()?,
+                y: dec.read_i32()?,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_stream_decoder_yields_typed_values() {
+        let mut buf = Vec::new();
+        {
+            let mut enc = AsyncEncoder::new(&mut buf);
+            for (x, y) in [(1, 2), (-3, 4)] {
+                let mut payload = Vec::new();
+                let mut point_enc = crate::wire::Encoder::new(&mut payload);
+                point_enc.write_i32(x).unwrap();
+                point_enc.write_i32(y).unwrap();
+                enc.write_message(&payload).await.unwrap();
+            }
+        }
+
+        let mut stream: AsyncStreamDecoder<&[u8], Point> = AsyncStreamDecoder::new(&buf[..]);
+        assert_eq!(
+            stream.next_message().await.unwrap(),
+            Some(Point { x: 1, y: 2 })
+        );
+        assert_eq!(
+            stream.next_message().await.unwrap(),
+            Some(Point { x: -3, y: 4 })
+        );
+        assert_eq!(stream.next_message().await.unwrap(), None);
+    }
+}
@@ -0,0 +1,280 @@
+//! `bytes` crate `Buf`/`BufMut` wire format encoding/decoding, behind the
+//! `bytes` feature
+//!
+//! Mirrors [`crate::wire`]'s core scalar/string/bytes surface (same
+//! little-endian layout, same `u32` length prefixes) but writes into
+//! anything implementing `bytes::BufMut` and reads from anything
+//! implementing `bytes::Buf`, so a caller already holding a reusable
+//! `BytesMut` can encode into it directly and cheaply `split` off a
+//! completed frame, and a caller holding a non-contiguous chained buffer
+//! (e.g. from a network stack) can decode without collapsing it into a
+//! contiguous `Vec` first.
+
+use crate::wire::{Error, Result};
+use bytes::{Buf, BufMut};
+
+/// Encoder for SDP wire format over a `BufMut` destination
+pub struct Encoder<B: BufMut> {
+    buf: B,
+}
+
+impl<B: BufMut> Encoder<B> {
+    pub fn new(buf: B) -> Self {
+        Self { buf }
+    }
+
+    /// Consume the encoder and return the underlying buffer
+    pub fn into_inner(self) -> B {
+        self.buf
+    }
+
+    /// Encode a boolean (1 byte: 0 or 1)
+    pub fn write_bool(&mut self, value: bool) -> Result<()> {
+        self.buf.put_u8(if value { 1 } else { 0 });
+        Ok(())
+    }
+
+    /// Encode an 8-bit unsigned integer
+    pub fn write_u8(&mut self, value: u8) -> Result<()> {
+        self.buf.put_u8(value);
+        Ok(())
+    }
+
+    /// Encode a 16-bit unsigned integer (little-endian)
+    pub fn write_u16(&mut self, value: u16) -> Result<()> {
+        self.buf.put_u16_le(value);
+        Ok(())
+    }
+
+    /// Encode a 32-bit unsigned integer (little-endian)
+    pub fn write_u32(&mut self, value: u32) -> Result<()> {
+        self.buf.put_u32_le(value);
+        Ok(())
+    }
+
+    /// Encode a 64-bit unsigned integer (little-endian)
+    pub fn write_u64(&mut self, value: u64) -> Result<()> {
+        self.buf.put_u64_le(value);
+        Ok(())
+    }
+
+    /// Encode a 32-bit IEEE 754 float (little-endian)
+    pub fn write_f32(&mut self, value: f32) -> Result<()> {
+        self.buf.put_f32_le(value);
+        Ok(())
+    }
+
+    /// Encode a 64-bit IEEE 754 float (little-endian)
+    pub fn write_f64(&mut self, value: f64) -> Result<()> {
+        self.buf.put_f64_le(value);
+        Ok(())
+    }
+
+    /// Encode a string (u32 length + UTF-8 bytes)
+    pub fn write_string(&mut self, value: &str) -> Result<()> {
+        self.write_bytes(value.as_bytes())
+    }
+
+    /// Encode a byte array (u32 length + bytes)
+    pub fn write_bytes(&mut self, value: &[u8]) -> Result<()> {
+        self.write_u32(value.len() as u32)?;
+        self.buf.put_slice(value);
+        Ok(())
+    }
+
+    /// Write one length-delimited message: a `u32` byte-length prefix
+    /// followed by `payload`, matching [`crate::wire::Encoder::write_message`]
+    pub fn write_message(&mut self, payload: &[u8]) -> Result<()> {
+        self.write_u32(payload.len() as u32)?;
+        self.buf.put_slice(payload);
+        Ok(())
+    }
+}
+
+/// Decoder for SDP wire format over a `Buf` source
+pub struct Decoder<B: Buf> {
+    buf: B,
+    /// Number of bytes consumed so far, so errors can be pinned to an
+    /// offset the same way [`crate::wire::Decoder`] does
+    bytes_read: u64,
+}
+
+impl<B: Buf> Decoder<B> {
+    pub fn new(buf: B) -> Self {
+        Self { buf, bytes_read: 0 }
+    }
+
+    /// Current byte offset into the source
+    pub fn position(&self) -> u64 {
+        self.bytes_read
+    }
+
+    fn require(&self, needed: usize) -> Result<()> {
+        if self.buf.remaining() < needed {
+            return Err(Error::UnexpectedEof {
+                offset: self.bytes_read,
+            });
+        }
+        Ok(())
+    }
+
+    /// Decode a boolean
+    pub fn read_bool(&mut self) -> Result<bool> {
+        let offset = self.bytes_read;
+        self.require(1)?;
+        let value = self.buf.get_u8();
+        self.bytes_read += 1;
+        match value {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => Err(Error::InvalidBool { offset, value }),
+        }
+    }
+
+    /// Decode an 8-bit unsigned integer
+    pub fn read_u8(&mut self) -> Result<u8> {
+        self.require(1)?;
+        let value = self.buf.get_u8();
+        self.bytes_read += 1;
+        Ok(value)
+    }
+
+    /// Decode a 16-bit unsigned integer (little-endian)
+    pub fn read_u16(&mut self) -> Result<u16> {
+        self.require(2)?;
+        let value = self.buf.get_u16_le();
+        self.bytes_read += 2;
+        Ok(value)
+    }
+
+    /// Decode a 32-bit unsigned integer (little-endian)
+    pub fn read_u32(&mut self) -> Result<u32> {
+        self.require(4)?;
+        let value = self.buf.get_u32_le();
+        self.bytes_read += 4;
+        Ok(value)
+    }
+
+    /// Decode a 64-bit unsigned integer (little-endian)
+    pub fn read_u64(&mut self) -> Result<u64> {
+        self.require(8)?;
+        let value = self.buf.get_u64_le();
+        self.bytes_read += 8;
+        Ok(value)
+    }
+
+    /// Decode a 32-bit IEEE 754 float (little-endian)
+    pub fn read_f32(&mut self) -> Result<f32> {
+        self.require(4)?;
+        let value = self.buf.get_f32_le();
+        self.bytes_read += 4;
+        Ok(value)
+    }
+
+    /// Decode a 64-bit IEEE 754 float (little-endian)
+    pub fn read_f64(&mut self) -> Result<f64> {
+        self.require(8)?;
+        let value = self.buf.get_f64_le();
+        self.bytes_read += 8;
+        Ok(value)
+    }
+
+    /// Decode a string (u32 length + UTF-8 bytes)
+    pub fn read_string(&mut self) -> Result<String> {
+        let offset = self.bytes_read;
+        let bytes = self.read_bytes()?;
+        String::from_utf8(bytes).map_err(|source| Error::InvalidUtf8 { offset, source })
+    }
+
+    /// Decode a byte array (u32 length + bytes), bounded by `MAX_ARRAY_SIZE`.
+    /// The length is checked against the buffer's remaining bytes before
+    /// anything is allocated, so a malformed length prefix can't trigger a
+    /// giant allocation on its own.
+    pub fn read_bytes(&mut self) -> Result<Vec<u8>> {
+        let offset = self.bytes_read;
+        let len = self.read_u32()?;
+        if len > crate::wire::MAX_ARRAY_SIZE {
+            return Err(Error::ArrayTooLarge {
+                offset,
+                size: len,
+                max: crate::wire::MAX_ARRAY_SIZE,
+            });
+        }
+        self.require(len as usize)?;
+        let mut out = vec![0u8; len as usize];
+        self.buf.copy_to_slice(&mut out);
+        self.bytes_read += len as u64;
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Bytes, BytesMut};
+
+    #[test]
+    fn test_scalar_roundtrip() {
+        let mut buf = BytesMut::new();
+        let mut enc = Encoder::new(&mut buf);
+        enc.write_bool(true).unwrap();
+        enc.write_u8(42).unwrap();
+        enc.write_u16(1000).unwrap();
+        enc.write_u32(100_000).unwrap();
+        enc.write_u64(u64::MAX).unwrap();
+        enc.write_f32(1.5).unwrap();
+        enc.write_f64(core::f64::consts::PI).unwrap();
+
+        let mut dec = Decoder::new(Bytes::from(buf.to_vec()));
+        assert!(dec.read_bool().unwrap());
+        assert_eq!(dec.read_u8().unwrap(), 42);
+        assert_eq!(dec.read_u16().unwrap(), 1000);
+        assert_eq!(dec.read_u32().unwrap(), 100_000);
+        assert_eq!(dec.read_u64().unwrap(), u64::MAX);
+        assert_eq!(dec.read_f32().unwrap(), 1.5);
+        assert_eq!(dec.read_f64().unwrap(), core::f64::consts::PI);
+    }
+
+    #[test]
+    fn test_string_and_bytes_roundtrip() {
+        let mut buf = BytesMut::new();
+        let mut enc = Encoder::new(&mut buf);
+        enc.write_string("hello").unwrap();
+        enc.write_bytes(&[1, 2, 3, 4]).unwrap();
+
+        let mut dec = Decoder::new(Bytes::from(buf.to_vec()));
+        assert_eq!(dec.read_string().unwrap(), "hello");
+        assert_eq!(dec.read_bytes().unwrap(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_decode_over_chained_buffer() {
+        // Two physically separate chunks, chained so `Buf` sees one logical
+        // stream without ever being collapsed into a single contiguous `Vec`.
+        let mut first = BytesMut::new();
+        Encoder::new(&mut first).write_u32(7).unwrap();
+        let mut second = BytesMut::new();
+        Encoder::new(&mut second).write_u32(9).unwrap();
+
+        let chained = Buf::chain(Bytes::from(first.to_vec()), Bytes::from(second.to_vec()));
+        let mut dec = Decoder::new(chained);
+        assert_eq!(dec.read_u32().unwrap(), 7);
+        assert_eq!(dec.read_u32().unwrap(), 9);
+    }
+
+    #[test]
+    fn test_read_errors_on_truncated_buffer() {
+        let mut dec = Decoder::new(Bytes::from(vec![0u8; 2]));
+        let err = dec.read_u32().unwrap_err();
+        assert!(matches!(err, Error::UnexpectedEof { offset: 0 }));
+    }
+
+    #[test]
+    fn test_read_bytes_rejects_oversized_length_prefix() {
+        let mut buf = BytesMut::new();
+        Encoder::new(&mut buf).write_u32(crate::wire::MAX_ARRAY_SIZE + 1).unwrap();
+        let mut dec = Decoder::new(Bytes::from(buf.to_vec()));
+        let err = dec.read_bytes().unwrap_err();
+        assert!(matches!(err, Error::ArrayTooLarge { .. }));
+    }
+}